@@ -7,7 +7,7 @@
 //! let example = "Document this crate".to_string();
 //! let mut task = todo_txt::Task::new(&example);
 //!
-//! task.projects.push("rust".to_string());
+//! task.description.push_str(" +rust");
 //! task.is_complete = true;
 //!
 //! assert_eq!(task.to_string(), "x Document this crate +rust".to_string());
@@ -15,16 +15,157 @@
 use lazy_static::lazy_static;
 use regex::Regex;
 use std::fmt;
+use std::io::{self, BufRead, Read, Write};
+
+lazy_static! {
+    // A tag is a whitespace-delimited token, so it only counts when preceded by whitespace or
+    // the start of the description; this keeps e.g. an email-like `foo@bar` in running text from
+    // being mistaken for a `@bar` context.
+    static ref PROJECT_EXP: Regex = Regex::new(r"(?:^|\s)\+(?P<tag>\S+)").unwrap();
+    static ref CONTEXT_EXP: Regex = Regex::new(r"(?:^|\s)@(?P<tag>\S+)").unwrap();
+    // The value half is required not to start with `/` so that a bare URL like `http://example.com`
+    // isn't mistaken for a `http` key with a `//example.com` value.
+    static ref KEY_VALUE_EXP: Regex = Regex::new(r"(?:^|\s)(?P<k>[^\s:]+):(?P<v>[^\s/]\S*)").unwrap();
+}
+
+/// A calendar date in `YYYY-MM-DD` form, as used by the todo.txt creation/completion date fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Date {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+}
+
+impl Date {
+    /// Creates a new date from its components.
+    pub fn new(year: i32, month: u8, day: u8) -> Self {
+        Self { year, month, day }
+    }
+
+    /// Parses a `YYYY-MM-DD` token, returning `None` if it isn't a well-formed date.
+    fn parse(s: &str) -> Option<Self> {
+        lazy_static! {
+            static ref DATE_EXP: Regex = Regex::new(r"^(\d{4})-(\d{2})-(\d{2})$").unwrap();
+        }
+
+        let captures = DATE_EXP.captures(s)?;
+        let year = captures.get(1)?.as_str().parse().ok()?;
+        let month = captures.get(2)?.as_str().parse().ok()?;
+        let day = captures.get(3)?.as_str().parse().ok()?;
+
+        if !(1..=12).contains(&month) || day < 1 || day > Self::days_in_month(year, month) {
+            return None;
+        }
+
+        Some(Self { year, month, day })
+    }
+
+    fn is_leap_year(year: i32) -> bool {
+        (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+    }
+
+    fn days_in_month(year: i32, month: u8) -> u8 {
+        match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if Self::is_leap_year(year) => 29,
+            2 => 28,
+            _ => unreachable!("month is always 1..=12"),
+        }
+    }
+
+    /// Returns the date `days` days after this one.
+    fn add_days(&self, days: i64) -> Self {
+        let mut year = self.year;
+        let mut month = self.month;
+        let mut day = self.day as i64 + days;
+
+        loop {
+            let days_in_month = Self::days_in_month(year, month) as i64;
+            if day > days_in_month {
+                day -= days_in_month;
+                month += 1;
+                if month > 12 {
+                    month = 1;
+                    year += 1;
+                }
+            } else if day < 1 {
+                month -= 1;
+                if month < 1 {
+                    month = 12;
+                    year -= 1;
+                }
+                day += Self::days_in_month(year, month) as i64;
+            } else {
+                break;
+            }
+        }
+
+        Self {
+            year,
+            month,
+            day: day as u8,
+        }
+    }
+
+    /// Returns the date `months` months after this one, clamping the day to the target month's
+    /// length.
+    fn add_months(&self, months: i64) -> Self {
+        let total = self.year as i64 * 12 + (self.month as i64 - 1) + months;
+        let year = total.div_euclid(12) as i32;
+        let month = (total.rem_euclid(12) + 1) as u8;
+        let day = self.day.min(Self::days_in_month(year, month));
+
+        Self { year, month, day }
+    }
+
+    /// Returns the date `years` years after this one.
+    fn add_years(&self, years: i64) -> Self {
+        self.add_months(years * 12)
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Splits the leading whitespace-delimited token off of `s` and, if it parses as a [Date],
+/// returns it along with the remainder. Otherwise returns `s` unchanged.
+fn take_leading_date(s: &str) -> (Option<Date>, &str) {
+    let (token, rest) = match s.split_once(char::is_whitespace) {
+        Some((token, rest)) => (token, rest),
+        None => (s, ""),
+    };
+
+    match Date::parse(token) {
+        Some(date) => (Some(date), rest),
+        None => (None, s),
+    }
+}
 
 /// A task.
+///
+/// `+project`, `@context`, and `key:value` tags are not split out into separate fields; they
+/// stay inline in [description](Task::description) at their original position, so a [Task] that
+/// hasn't been mutated round-trips back to the line it was parsed from. Whitespace is not kept
+/// verbatim: leading and trailing whitespace is dropped, and the separator after the `x`, the
+/// priority, and each leading date is written back as a single space. Use
+/// [projects](Task::projects), [contexts](Task::contexts), and [attributes](Task::attributes) to
+/// read them.
+///
+/// With the `serde` feature enabled, this derives `Serialize`/`Deserialize` with each field
+/// mapped directly, so a [Task] can round-trip through JSON without a separate conversion type.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Task {
     pub is_complete: bool,
     pub priority: Option<char>,
+    pub creation_date: Option<Date>,
+    pub completion_date: Option<Date>,
     pub description: String,
-    pub projects: Vec<String>,
-    pub contexts: Vec<String>,
-    pub attributes: Vec<(String, String)>,
 }
 
 impl Task {
@@ -39,11 +180,13 @@ impl Task {
     /// assert_eq!(task, todo_txt::Task {
     ///     is_complete: false,
     ///     priority: Some('B'),
-    ///     description: "Write some code".to_string(),
-    ///     projects: vec!["rust".to_string()],
-    ///     contexts: vec!["work".to_string()],
-    ///     attributes: vec![("due".to_string(), "tomorrow".to_string())],
+    ///     creation_date: None,
+    ///     completion_date: None,
+    ///     description: "Write some code +rust @work due:tomorrow".to_string(),
     /// });
+    /// assert_eq!(task.projects(), vec!["rust".to_string()]);
+    /// assert_eq!(task.contexts(), vec!["work".to_string()]);
+    /// assert_eq!(task.attributes(), vec![("due".to_string(), "tomorrow".to_string())]);
     /// ```
     ///
     /// ```
@@ -53,67 +196,152 @@ impl Task {
     /// assert_eq!(task, todo_txt::Task {
     ///     is_complete: true,
     ///     priority: None,
-    ///     description: "Buy eggs".to_string(),
-    ///     projects: vec![],
-    ///     contexts: vec!["shopping".to_string(), "home".to_string()],
-    ///     attributes: vec![],
+    ///     creation_date: None,
+    ///     completion_date: None,
+    ///     description: "Buy eggs @shopping @home".to_string(),
     /// });
+    /// assert_eq!(task.contexts(), vec!["shopping".to_string(), "home".to_string()]);
+    /// ```
+    ///
+    /// A completed task may carry both a completion and creation date, while an incomplete task
+    /// may only carry a creation date, per the todo.txt spec. Dates elsewhere in the description
+    /// are left untouched.
+    ///
+    /// ```
+    /// let example = "x 2021-01-03 2021-01-01 Write some code due:tomorrow".to_string();
+    /// let task = todo_txt::Task::new(&example);
+    ///
+    /// assert_eq!(task.creation_date, Some(todo_txt::Date::new(2021, 1, 1)));
+    /// assert_eq!(task.completion_date, Some(todo_txt::Date::new(2021, 1, 3)));
+    /// assert_eq!(task.attributes(), vec![("due".to_string(), "tomorrow".to_string())]);
+    /// ```
+    ///
+    /// Nothing was stripped out of the description, so re-serializing this unmodified task
+    /// reproduces the original line exactly:
+    ///
+    /// ```
+    /// let example = "Call +work Bob @phone".to_string();
+    /// let task = todo_txt::Task::new(&example);
+    ///
+    /// assert_eq!(task.to_string(), example);
     /// ```
     pub fn new(task: &String) -> Self {
         lazy_static! {
             static ref TASK_EXP: Regex =
                 Regex::new("(?P<c>x\\s)?(?P<p>\\([A-Z]\\)\\s)?(?P<d>.*)").unwrap();
-            static ref PROJECT_EXP: Regex = Regex::new("\\+\\w+").unwrap();
-            static ref CONTEXT_EXP: Regex = Regex::new("@\\w+").unwrap();
-            static ref KEY_VALUE_EXP: Regex = Regex::new("(?P<k>\\w+):(?P<v>\\w+)").unwrap();
         }
 
         // We can unwrap this because there might be an empty line, but there will never be
         // a `None` description.
-        let captures = TASK_EXP.captures(&task).unwrap();
+        let captures = TASK_EXP.captures(task).unwrap();
 
         let is_complete = captures.name("c").is_some();
-        let priority = if let Some(priority) = captures.name("p") {
-            priority.as_str().chars().nth(1)
-        } else {
-            None
-        };
+        let priority = captures.name("p").and_then(|p| p.as_str().chars().nth(1));
 
         // We can unwrap this because there might be an empty description, but there will never be
         // a `None` description.
         let description = captures.name("d").unwrap().as_str();
 
-        let projects = PROJECT_EXP
-            .find_iter(description)
-            .map(|m| m.as_str().get(1..).unwrap().to_string()) // If `PROJECT_EXP` matched, there is always at least one char.
-            .collect();
-        let description = PROJECT_EXP.replace_all(description, "").to_string();
+        // A completion date is only ever recognized alongside a creation date, as the first of
+        // the two tokens right after the priority (or after the `x` if there is no priority); it
+        // is followed by the creation date. On an incomplete task a creation date is only ever
+        // recognized immediately after the priority (or at the start of the description if there
+        // is no priority). Anything that doesn't parse as a date in these leading positions is
+        // left alone as plain description text.
+        let (completion_date, creation_date, description) = if is_complete {
+            match take_leading_date(description) {
+                (Some(completion_date), rest) => match take_leading_date(rest) {
+                    (Some(creation_date), rest) => (Some(completion_date), Some(creation_date), rest),
+                    (None, _) => (None, None, description),
+                },
+                (None, _) => (None, None, description),
+            }
+        } else {
+            let (creation_date, rest) = take_leading_date(description);
+            (None, creation_date, rest)
+        };
 
-        let contexts = CONTEXT_EXP
-            .find_iter(&description)
-            .map(|m| m.as_str().get(1..).unwrap().to_string()) // If `CONTEXT_EXP` matched, there is always at least one char.
-            .collect();
-        let description = CONTEXT_EXP.replace_all(&description, "").to_string();
+        Self {
+            is_complete,
+            priority,
+            creation_date,
+            completion_date,
+            description: description.trim().to_string(),
+        }
+    }
+
+    /// Returns the `+project` tags found in [description](Task::description), in order.
+    ///
+    /// A project name may contain any non-whitespace characters, including hyphens, so
+    /// `+my-project` is not truncated at the hyphen; trailing punctuation attached to the tag is
+    /// preserved as part of it, matching how real todo.txt editors treat whitespace-delimited
+    /// tokens.
+    ///
+    /// ```
+    /// let task = todo_txt::Task::new(&"Fix the bug +my-project, finally".to_string());
+    ///
+    /// assert_eq!(task.projects(), vec!["my-project,".to_string()]);
+    /// ```
+    pub fn projects(&self) -> Vec<String> {
+        PROJECT_EXP
+            .captures_iter(&self.description)
+            .map(|c| c.name("tag").unwrap().as_str().to_string())
+            .collect()
+    }
+
+    /// Returns the `@context` tags found in [description](Task::description), in order.
+    ///
+    /// A context name may contain any non-whitespace characters, including hyphens, so
+    /// `@in-box` is not truncated at the hyphen.
+    ///
+    /// ```
+    /// let task = todo_txt::Task::new(&"File it @in-box".to_string());
+    ///
+    /// assert_eq!(task.contexts(), vec!["in-box".to_string()]);
+    /// ```
+    pub fn contexts(&self) -> Vec<String> {
+        CONTEXT_EXP
+            .captures_iter(&self.description)
+            .map(|c| c.name("tag").unwrap().as_str().to_string())
+            .collect()
+    }
 
-        let attributes = KEY_VALUE_EXP
-            .captures_iter(&description)
+    /// Returns the `key:value` attributes found in [description](Task::description), in order.
+    ///
+    /// An attribute value may contain any non-whitespace characters, so date- and URL-valued
+    /// attributes like `due:2024-01-05` and `url:http://example.com` parse in full. A bare URL in
+    /// the description, whose value half would start with `//`, is left alone as plain text
+    /// rather than mistaken for a `key:value` pair.
+    ///
+    /// ```
+    /// let task = todo_txt::Task::new(
+    ///     &"Pay the bill due:2024-01-05 url:http://example.com".to_string(),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     task.attributes(),
+    ///     vec![
+    ///         ("due".to_string(), "2024-01-05".to_string()),
+    ///         ("url".to_string(), "http://example.com".to_string()),
+    ///     ]
+    /// );
+    /// ```
+    ///
+    /// ```
+    /// let task = todo_txt::Task::new(&"See http://example.com for details".to_string());
+    ///
+    /// assert!(task.attributes().is_empty());
+    /// ```
+    pub fn attributes(&self) -> Vec<(String, String)> {
+        KEY_VALUE_EXP
+            .captures_iter(&self.description)
             .map(|c| {
                 (
                     c.name("k").unwrap().as_str().to_string(),
                     c.name("v").unwrap().as_str().to_string(),
                 )
             })
-            .collect();
-        let description = KEY_VALUE_EXP.replace_all(&description, "").to_string();
-
-        Self {
-            is_complete,
-            priority,
-            description: description.trim().to_string(),
-            projects,
-            contexts,
-            attributes,
-        }
+            .collect()
     }
 
     /// Returns the task as a string.
@@ -124,14 +352,25 @@ impl Task {
     /// let task = todo_txt::Task {
     ///     is_complete: true,
     ///     priority: Some('C'),
-    ///     description: "Take out the trash".to_string(),
-    ///     projects: vec![],
-    ///     contexts: vec!["home".to_string()],
-    ///     attributes: vec![("day".to_string(), "wednesdays".to_string())],
+    ///     creation_date: None,
+    ///     completion_date: None,
+    ///     description: "Take out the trash @home day:wednesdays".to_string(),
     /// };
     ///
     /// assert_eq!(task.to_string(), "x (C) Take out the trash @home day:wednesdays".to_string());
     /// ```
+    ///
+    /// Creation and completion dates, when present, are re-emitted in the order required by the
+    /// spec: `x <completion> <creation>` for a done task, `(P) <creation>` otherwise.
+    ///
+    /// ```
+    /// let mut task = todo_txt::Task::new(&"Write some code".to_string());
+    /// task.creation_date = Some(todo_txt::Date::new(2021, 1, 1));
+    /// task.is_complete = true;
+    /// task.completion_date = Some(todo_txt::Date::new(2021, 1, 3));
+    ///
+    /// assert_eq!(task.to_string(), "x 2021-01-03 2021-01-01 Write some code".to_string());
+    /// ```
     pub fn to_string(&self) -> String {
         let mut string = String::new();
 
@@ -143,22 +382,107 @@ impl Task {
             string.push_str(&format!("({}) ", p));
         }
 
-        string.push_str(&format!("{} ", &self.description));
-
-        self.projects.iter().for_each(|project| {
-            string.push_str(&format!("+{} ", project));
-        });
+        if self.is_complete {
+            if let Some(completion_date) = self.completion_date {
+                string.push_str(&format!("{} ", completion_date));
+            }
+        }
 
-        self.contexts.iter().for_each(|context| {
-            string.push_str(&format!("@{} ", context));
-        });
+        if let Some(creation_date) = self.creation_date {
+            string.push_str(&format!("{} ", creation_date));
+        }
 
-        self.attributes.iter().for_each(|(key, value)| {
-            string.push_str(&format!("{}:{} ", key, value));
-        });
+        string.push_str(&self.description);
 
         string.trim_end().to_string()
     }
+
+    /// Given that this task was completed on `completed_on`, returns its next occurrence if it
+    /// carries a `rec:` attribute whose value parses as an amount and a `d`/`w`/`m`/`y` unit, or
+    /// `None` otherwise.
+    ///
+    /// A strict recurrence (e.g. `rec:1w`) bases the new `due:` date on the old one; a relative
+    /// recurrence (e.g. `rec:+1w`) bases it on `completed_on` instead. Priority, projects,
+    /// contexts, and other attributes are carried over; the new task is incomplete with a fresh
+    /// creation date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let task = todo_txt::Task {
+    ///     is_complete: true,
+    ///     priority: Some('A'),
+    ///     creation_date: None,
+    ///     completion_date: Some(todo_txt::Date::new(2021, 1, 3)),
+    ///     description: "Water the plants rec:1w".to_string(),
+    /// };
+    ///
+    /// let next = task.recur(todo_txt::Date::new(2021, 1, 3)).unwrap();
+    ///
+    /// assert!(!next.is_complete);
+    /// assert_eq!(next.creation_date, Some(todo_txt::Date::new(2021, 1, 3)));
+    /// assert!(next.description.contains("rec:1w"));
+    /// ```
+    pub fn recur(&self, completed_on: Date) -> Option<Task> {
+        let attributes = self.attributes();
+        let rec_value = &attributes.iter().find(|(k, _)| k == "rec")?.1;
+        let (amount, unit, relative) = parse_recurrence(rec_value)?;
+
+        let base = if relative {
+            completed_on
+        } else {
+            attributes
+                .iter()
+                .find(|(k, _)| k == "due")
+                .and_then(|(_, v)| Date::parse(v))
+                .unwrap_or(completed_on)
+        };
+
+        let due = match unit {
+            'd' => base.add_days(amount),
+            'w' => base.add_days(amount * 7),
+            'm' => base.add_months(amount),
+            'y' => base.add_years(amount),
+            _ => unreachable!("parse_recurrence only returns d/w/m/y units"),
+        };
+
+        let description = if attributes.iter().any(|(k, _)| k == "due") {
+            lazy_static! {
+                static ref DUE_EXP: Regex = Regex::new(r"\bdue:\S+").unwrap();
+            }
+            DUE_EXP
+                .replace(&self.description, format!("due:{}", due))
+                .to_string()
+        } else {
+            format!("{} due:{}", self.description, due)
+        };
+
+        Some(Task {
+            is_complete: false,
+            priority: self.priority,
+            creation_date: Some(completed_on),
+            completion_date: None,
+            description,
+        })
+    }
+}
+
+/// Parses a `rec:` attribute value (e.g. `1w`, `+2m`, `3d`) into an amount, a unit
+/// (`d`/`w`/`m`/`y`), and whether it is relative to the completion date rather than strict
+/// against the old due date.
+fn parse_recurrence(value: &str) -> Option<(i64, char, bool)> {
+    let relative = value.starts_with('+');
+    let value = value.strip_prefix('+').unwrap_or(value);
+    let split_at = value.len().checked_sub(1)?;
+    let (amount, unit) = value.split_at(split_at);
+    let amount = amount.parse().ok()?;
+    let unit = unit.chars().next()?;
+
+    if !matches!(unit, 'd' | 'w' | 'm' | 'y') {
+        return None;
+    }
+
+    Some((amount, unit, relative))
 }
 
 impl fmt::Display for Task {
@@ -166,3 +490,210 @@ impl fmt::Display for Task {
         write!(f, "{}", self.to_string())
     }
 }
+
+/// A list of tasks, typically corresponding to the contents of a todo.txt file.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TaskList {
+    pub tasks: Vec<Task>,
+}
+
+impl TaskList {
+    /// Parses a list of tasks from a string, one [Task] per line.
+    ///
+    /// Blank lines are not meaningful todo.txt entries, so they are skipped by default. Use
+    /// [TaskList::from_str_keep_empty] to keep them as empty tasks. Either way, a line ending
+    /// after the last line is not kept: [TaskList::to_string] writes none after the last task.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list = todo_txt::TaskList::from_str("Buy milk\n\nWrite some code +rust");
+    ///
+    /// assert_eq!(list.tasks.len(), 2);
+    /// ```
+    pub fn from_str(s: &str) -> Self {
+        let tasks = s
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| Task::new(&line.to_string()))
+            .collect();
+
+        Self { tasks }
+    }
+
+    /// Parses a list of tasks from a string, preserving blank lines as empty tasks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list = todo_txt::TaskList::from_str_keep_empty("Buy milk\n\nWrite some code +rust");
+    ///
+    /// assert_eq!(list.tasks.len(), 3);
+    /// assert_eq!(list.tasks[1].description, "".to_string());
+    /// ```
+    pub fn from_str_keep_empty(s: &str) -> Self {
+        let tasks = s.lines().map(|line| Task::new(&line.to_string())).collect();
+
+        Self { tasks }
+    }
+
+    /// Reads a list of tasks from any [Read] implementation, one [Task] per line.
+    ///
+    /// Blank lines are skipped, matching [TaskList::from_str].
+    pub fn from_reader<R: Read>(reader: R) -> io::Result<Self> {
+        let tasks = io::BufReader::new(reader)
+            .lines()
+            .filter_map(|line| match line {
+                Ok(line) if line.trim().is_empty() => None,
+                Ok(line) => Some(Ok(Task::new(&line))),
+                Err(err) => Some(Err(err)),
+            })
+            .collect::<io::Result<Vec<Task>>>()?;
+
+        Ok(Self { tasks })
+    }
+
+    /// Returns the task list as a string, one [Task] per line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let list = todo_txt::TaskList::from_str("Buy milk\nWrite some code +rust");
+    ///
+    /// assert_eq!(list.to_string(), "Buy milk\nWrite some code +rust".to_string());
+    /// ```
+    pub fn to_string(&self) -> String {
+        self.tasks
+            .iter()
+            .map(|task| task.to_string())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    /// Writes the task list to any [Write] implementation, one [Task] per line.
+    pub fn write<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writer.write_all(self.to_string().as_bytes())
+    }
+}
+
+impl fmt::Display for TaskList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_string())
+    }
+}
+
+/// The completion/content status a [Filter] should select for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TodoStatus {
+    /// Tasks that are not complete and have a non-empty description.
+    Active,
+    /// Tasks that are complete.
+    Done,
+    /// Tasks with a blank description.
+    Empty,
+    /// Every task, regardless of status.
+    All,
+}
+
+/// A builder for querying a [TaskList] by status and property ranges.
+///
+/// # Examples
+///
+/// ```
+/// use todo_txt::{Filter, TaskList};
+///
+/// let list = TaskList::from_str("(A) Fix the bug +rust\n(B) Write docs +rust\nx Done already");
+/// let filter = Filter::new().priorities('A'..='B').project("rust");
+///
+/// assert_eq!(list.filter(&filter).len(), 2);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Filter {
+    status: TodoStatus,
+    priorities: Option<std::ops::RangeInclusive<char>>,
+    projects: Vec<String>,
+    contexts: Vec<String>,
+}
+
+impl Default for Filter {
+    /// Defaults to [TodoStatus::Active], hiding completed and empty tasks, matching the
+    /// default listing behavior of common todo.txt tooling.
+    fn default() -> Self {
+        Self {
+            status: TodoStatus::Active,
+            priorities: None,
+            projects: Vec::new(),
+            contexts: Vec::new(),
+        }
+    }
+}
+
+impl Filter {
+    /// Creates a new filter that selects [TodoStatus::Active] tasks.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Selects tasks matching the given [TodoStatus] instead of the default.
+    pub fn status(mut self, status: TodoStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Restricts matches to tasks with a priority in the given inclusive range.
+    pub fn priorities(mut self, priorities: std::ops::RangeInclusive<char>) -> Self {
+        self.priorities = Some(priorities);
+        self
+    }
+
+    /// Restricts matches to tasks tagged with the given project, in addition to any other
+    /// projects already required by this filter.
+    pub fn project(mut self, project: &str) -> Self {
+        self.projects.push(project.to_string());
+        self
+    }
+
+    /// Restricts matches to tasks tagged with the given context, in addition to any other
+    /// contexts already required by this filter.
+    pub fn context(mut self, context: &str) -> Self {
+        self.contexts.push(context.to_string());
+        self
+    }
+
+    fn matches(&self, task: &Task) -> bool {
+        let status_matches = match self.status {
+            TodoStatus::Active => !task.is_complete && !task.description.trim().is_empty(),
+            TodoStatus::Done => task.is_complete,
+            TodoStatus::Empty => task.description.trim().is_empty(),
+            TodoStatus::All => true,
+        };
+
+        let priority_matches = match &self.priorities {
+            Some(range) => task.priority.map_or(false, |p| range.contains(&p)),
+            None => true,
+        };
+
+        let task_projects = task.projects();
+        let projects_match = self
+            .projects
+            .iter()
+            .all(|project| task_projects.contains(project));
+
+        let task_contexts = task.contexts();
+        let contexts_match = self
+            .contexts
+            .iter()
+            .all(|context| task_contexts.contains(context));
+
+        status_matches && priority_matches && projects_match && contexts_match
+    }
+}
+
+impl TaskList {
+    /// Selects the tasks in this list matching the given [Filter].
+    ///
+    /// Returns references into this list rather than cloning tasks.
+    pub fn filter(&self, filter: &Filter) -> Vec<&Task> {
+        self.tasks.iter().filter(|task| filter.matches(task)).collect()
+    }
+}